@@ -1,10 +1,52 @@
+use alloc::format;
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// パース済みのホスト
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Host {
+    /// ドメイン名
+    Domain(String),
+    /// IPv4アドレス
+    Ipv4([u8; 4]),
+    /// IPv6アドレス
+    Ipv6([u16; 8]),
+}
+
+/// URLのスキーム
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Scheme {
+    Http,
+    Https,
+    /// この実装では扱えないスキーム
+    Unknown(String),
+}
+
+impl Scheme {
+    fn as_str(&self) -> &str {
+        match self {
+            Scheme::Http => "http",
+            Scheme::Https => "https",
+            Scheme::Unknown(scheme) => scheme,
+        }
+    }
+
+    /// スキームの既定のポート番号
+    fn default_port(&self) -> &'static str {
+        match self {
+            Scheme::Https => "443",
+            _ => "80",
+        }
+    }
+}
 
 /// URL
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Url {
     /// URL全体
     url: String,
+    /// スキーム
+    scheme: Scheme,
     /// 完全修飾ドメイン名またはIPアドレス
     host: String,
     /// ポート番号
@@ -13,16 +55,20 @@ pub struct Url {
     path: String,
     /// クエリパラメータ
     searchpart: String,
+    /// フラグメント
+    fragment: String,
 }
 
 impl Url {
     pub const fn new(url: String) -> Self {
         Self {
             url,
+            scheme: Scheme::Unknown(String::new()),
             host: String::new(),
             port: String::new(),
             path: String::new(),
             searchpart: String::new(),
+            fragment: String::new(),
         }
     }
 
@@ -30,6 +76,30 @@ impl Url {
         &self.host
     }
 
+    pub fn scheme(&self) -> &Scheme {
+        &self.scheme
+    }
+
+    /// ホストをドメイン名/IPv4/IPv6として解釈する
+    pub fn host_parsed(&self) -> Host {
+        if let Some(literal) = self
+            .host
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+        {
+            if let Some(groups) = parse_ipv6(literal) {
+                return Host::Ipv6(groups);
+            }
+            return Host::Domain(self.host.clone());
+        }
+
+        if let Some(octets) = parse_ipv4(&self.host) {
+            return Host::Ipv4(octets);
+        }
+
+        Host::Domain(self.host.clone())
+    }
+
     pub fn port(&self) -> &str {
         &self.port
     }
@@ -42,29 +112,150 @@ impl Url {
         &self.searchpart
     }
 
+    pub fn fragment(&self) -> &str {
+        &self.fragment
+    }
+
+    /// 各要素から正規なURL文字列を再構築する
+    pub fn serialize(&self) -> String {
+        let mut result = format!("{}://{}", self.scheme.as_str(), self.host);
+
+        if self.port != self.scheme.default_port() {
+            result.push(':');
+            result.push_str(&self.port);
+        }
+
+        if !self.path.is_empty() || !self.searchpart.is_empty() || !self.fragment.is_empty() {
+            result.push('/');
+            result.push_str(&self.path);
+        }
+
+        if !self.searchpart.is_empty() {
+            result.push('?');
+            result.push_str(&self.searchpart);
+        }
+
+        if !self.fragment.is_empty() {
+            result.push('#');
+            result.push_str(&self.fragment);
+        }
+
+        result
+    }
+
+    /// [`serialize`](Self::serialize)のエイリアス
+    pub fn as_str(&self) -> String {
+        self.serialize()
+    }
+
+    /// クエリパラメータを`(key, value)`の組として走査する
+    ///
+    /// `&`で区切り，各組を最初の`=`で分割する（`=`が無い場合valueは空文字列になる）。
+    /// `+`はスペースに，`%XX`はパーセントエンコーディングとしてデコードする。
+    /// 不正な`%`エスケープはデコードせずそのまま残す。
+    pub fn query_pairs(&self) -> impl Iterator<Item = (String, String)> + '_ {
+        self.searchpart
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let mut key_and_value = pair.splitn(2, '=');
+                let key = key_and_value.next().unwrap_or("");
+                let value = key_and_value.next().unwrap_or("");
+                (percent_decode(key), percent_decode(value))
+            })
+    }
+
+    /// `reference` を自身(ベースURL)に対して解決し，新しい`Url`を返す
+    ///
+    /// RFC 3986の relative resolution に従う:
+    /// - `http://`または`https://`から始まる場合は絶対URLとしてそのままパースする
+    /// - `//`から始まる場合はスキームだけを引き継ぐ
+    /// - `/`から始まる場合はベースのパスを丸ごと置き換える
+    /// - `?`から始まる場合はクエリだけを置き換える
+    /// - `#`から始まる場合はフラグメントだけを置き換える
+    /// - それ以外の場合はベースのパスの最後の`/`までの部分にreferenceを連結し，
+    ///   `.`と`..`セグメントを正規化する
+    pub fn join(&self, reference: &str) -> Result<Url, String> {
+        if reference.starts_with("http://") || reference.starts_with("https://") {
+            return Url::new(reference.to_string()).parse().cloned();
+        }
+
+        if let Some(rest) = reference.strip_prefix("//") {
+            return Url::new(format!("{}://{}", self.scheme.as_str(), rest))
+                .parse()
+                .cloned();
+        }
+
+        let authority = if self.port == self.scheme.default_port() {
+            self.host.clone()
+        } else {
+            format!("{}:{}", self.host, self.port)
+        };
+
+        let rest = if let Some(path) = reference.strip_prefix('/') {
+            format!("/{}", normalize_path(path))
+        } else if let Some(query) = reference.strip_prefix('?') {
+            format!("/{}?{}", self.path, query)
+        } else if let Some(fragment) = reference.strip_prefix('#') {
+            let mut rest = format!("/{}", self.path);
+            if !self.searchpart.is_empty() {
+                rest.push('?');
+                rest.push_str(&self.searchpart);
+            }
+            rest.push('#');
+            rest.push_str(fragment);
+            rest
+        } else {
+            let base_dir = match self.path.rfind('/') {
+                Some(index) => &self.path[..=index],
+                None => "",
+            };
+            format!("/{}", normalize_path(&format!("{}{}", base_dir, reference)))
+        };
+
+        Url::new(format!("{}://{}{}", self.scheme.as_str(), authority, rest))
+            .parse()
+            .cloned()
+    }
+
     /// URLをパースする
     pub fn parse(&mut self) -> Result<&Self, String> {
-        if !self.is_http() {
-            return Err("Only HTTP is supported.".to_string());
+        self.scheme = self.extract_scheme();
+
+        if let Scheme::Unknown(scheme) = &self.scheme {
+            return Err(format!("Unsupported scheme: {}", scheme));
         }
 
         self.host = self.extract_host();
         self.port = self.extract_port();
         self.path = self.extract_path();
         self.searchpart = self.extract_searchpart();
+        self.fragment = self.extract_fragment();
 
         Ok(self)
     }
 
-    /// スキームがHTTPかどうか
-    fn is_http(&self) -> bool {
-        self.url.contains("http://")
+    /// スキームを抽出する
+    fn extract_scheme(&self) -> Scheme {
+        match self.url.split_once("://") {
+            Some(("http", _)) => Scheme::Http,
+            Some(("https", _)) => Scheme::Https,
+            Some((scheme, _)) => Scheme::Unknown(scheme.to_string()),
+            None => Scheme::Unknown(String::new()),
+        }
     }
 
     /// ホスト名を抽出する
     fn extract_host(&self) -> String {
         let first_part = url_parts(&self.url).nth(0).unwrap();
 
+        // IPv6リテラルは`]`までをホストとする（`:`がポート区切りと紛らわしいため）
+        if let Some(rest) = first_part.strip_prefix('[') {
+            if let Some(end) = rest.find(']') {
+                return format!("[{}]", &rest[..end]);
+            }
+        }
+
         // ポート番号を取り除く
         if let Some(index) = first_part.find(':') {
             first_part[..index].to_string()
@@ -76,11 +267,22 @@ impl Url {
     /// ポート番号を抽出する
     fn extract_port(&self) -> String {
         let first_part = url_parts(&self.url).nth(0).unwrap();
+        let default_port = self.scheme.default_port();
+
+        // IPv6リテラルは`]`の後ろの`:`だけをポート区切りとみなす
+        if let Some(rest) = first_part.strip_prefix('[') {
+            if let Some(end) = rest.find(']') {
+                return match rest[end + 1..].strip_prefix(':') {
+                    Some(port) => port.to_string(),
+                    None => default_port.to_string(),
+                };
+            }
+        }
 
         if let Some(index) = first_part.find(':') {
             first_part[index + 1..].to_string()
         } else {
-            "80".to_string()
+            default_port.to_string()
         }
     }
 
@@ -93,10 +295,13 @@ impl Url {
             return String::new();
         }
 
-        // クエリパラメータを取り除く
+        // フラグメントとクエリパラメータを取り除く
         url_parts
             .nth(1)
             .unwrap()
+            .splitn(2, '#')
+            .nth(0)
+            .unwrap()
             .split('?')
             .nth(0)
             .unwrap()
@@ -111,8 +316,11 @@ impl Url {
             return String::new();
         }
 
+        // フラグメントを取り除く
+        let path_and_query = url_parts.nth(1).unwrap().splitn(2, '#').nth(0).unwrap();
+
         // [パス, クエリパラメータ]
-        let mut path_and_searchpart = url_parts.nth(1).unwrap().splitn(2, '?');
+        let mut path_and_searchpart = path_and_query.splitn(2, '?');
 
         if path_and_searchpart.clone().count() < 2 {
             String::new()
@@ -120,26 +328,190 @@ impl Url {
             path_and_searchpart.nth(1).unwrap().to_string()
         }
     }
+
+    /// フラグメントを抽出する
+    fn extract_fragment(&self) -> String {
+        let mut url_parts = url_parts(&self.url);
+
+        // パスが存在しない
+        if url_parts.clone().count() < 2 {
+            return String::new();
+        }
+
+        // [パス&クエリ, フラグメント]
+        let mut path_query_and_fragment = url_parts.nth(1).unwrap().splitn(2, '#');
+
+        if path_query_and_fragment.clone().count() < 2 {
+            String::new()
+        } else {
+            path_query_and_fragment.nth(1).unwrap().to_string()
+        }
+    }
+}
+
+impl core::str::FromStr for Url {
+    type Err = String;
+
+    /// URL文字列をパースして`Url`を生成する
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Url::new(s.to_string()).parse().cloned()
+    }
+}
+
+impl core::fmt::Display for Url {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.serialize())
+    }
 }
 
 /// スキームを取り除き，URLをホスト名とパス&クエリに分割する
 fn url_parts(full_url: &str) -> impl Iterator<Item = &str> + Clone {
-    full_url.trim_start_matches("http://").splitn(2, '/')
+    let without_scheme = match full_url.split_once("://") {
+        Some((_, rest)) => rest,
+        None => full_url,
+    };
+    without_scheme.splitn(2, '/')
+}
+
+/// パス中の`.`と`..`セグメントを，スタックを使って正規化する
+fn normalize_path(path: &str) -> String {
+    let mut stack: Vec<&str> = Vec::new();
+
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                stack.pop();
+            }
+            _ => stack.push(segment),
+        }
+    }
+
+    stack.join("/")
+}
+
+/// `+`をスペースに変換し，`%XX`をパーセントデコードする
+///
+/// 不正な`%`エスケープはデコードせずそのまま残す。
+/// デコード結果がUTF-8として不正な場合は，入力をそのまま返す。
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => {
+                decoded.push(b' ');
+                i += 1;
+            }
+            b'%' => {
+                let hi = bytes.get(i + 1).copied().and_then(hex_digit);
+                let lo = bytes.get(i + 2).copied().and_then(hex_digit);
+
+                if let (Some(hi), Some(lo)) = (hi, lo) {
+                    decoded.push((hi << 4) | lo);
+                    i += 3;
+                } else {
+                    decoded.push(b'%');
+                    i += 1;
+                }
+            }
+            byte => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8(decoded).unwrap_or_else(|_| input.to_string())
+}
+
+/// 16進数字を数値に変換する
+fn hex_digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// 4つのドット区切りの10進数からなるIPv4アドレスをパースする
+fn parse_ipv4(host: &str) -> Option<[u8; 4]> {
+    let parts: Vec<&str> = host.split('.').collect();
+
+    if parts.len() != 4 {
+        return None;
+    }
+
+    let mut octets = [0u8; 4];
+    for (i, part) in parts.iter().enumerate() {
+        octets[i] = part.parse().ok()?;
+    }
+
+    Some(octets)
+}
+
+/// `::`による省略を展開しつつ，8つの16進ハイテットからなるIPv6アドレスをパースする
+fn parse_ipv6(address: &str) -> Option<[u16; 8]> {
+    let mut groups = [0u16; 8];
+
+    if let Some((head, tail)) = address.split_once("::") {
+        let head_groups: Vec<&str> = if head.is_empty() {
+            Vec::new()
+        } else {
+            head.split(':').collect()
+        };
+        let tail_groups: Vec<&str> = if tail.is_empty() {
+            Vec::new()
+        } else {
+            tail.split(':').collect()
+        };
+
+        if head_groups.len() + tail_groups.len() > 8 {
+            return None;
+        }
+
+        for (i, part) in head_groups.iter().enumerate() {
+            groups[i] = u16::from_str_radix(part, 16).ok()?;
+        }
+
+        let tail_start = 8 - tail_groups.len();
+        for (i, part) in tail_groups.iter().enumerate() {
+            groups[tail_start + i] = u16::from_str_radix(part, 16).ok()?;
+        }
+    } else {
+        let parts: Vec<&str> = address.split(':').collect();
+
+        if parts.len() != 8 {
+            return None;
+        }
+
+        for (i, part) in parts.iter().enumerate() {
+            groups[i] = u16::from_str_radix(part, 16).ok()?;
+        }
+    }
+
+    Some(groups)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec;
 
     #[test]
     fn test_url_host() {
         let url = "http://example.com".to_string();
         let expected = Ok(Url {
             url: url.clone(),
+            scheme: Scheme::Http,
             host: "example.com".to_string(),
             port: "80".to_string(),
             path: "".to_string(),
             searchpart: "".to_string(),
+            fragment: "".to_string(),
         });
 
         assert_eq!(expected, Url::new(url).parse().cloned());
@@ -150,10 +522,12 @@ mod tests {
         let url = "http://example.com:8888".to_string();
         let expected = Ok(Url {
             url: url.clone(),
+            scheme: Scheme::Http,
             host: "example.com".to_string(),
             port: "8888".to_string(),
             path: "".to_string(),
             searchpart: "".to_string(),
+            fragment: "".to_string(),
         });
 
         assert_eq!(expected, Url::new(url).parse().cloned());
@@ -164,10 +538,12 @@ mod tests {
         let url = "http://example.com:8888/index.html".to_string();
         let expected = Ok(Url {
             url: url.clone(),
+            scheme: Scheme::Http,
             host: "example.com".to_string(),
             port: "8888".to_string(),
             path: "index.html".to_string(),
             searchpart: "".to_string(),
+            fragment: "".to_string(),
         });
 
         assert_eq!(expected, Url::new(url).parse().cloned());
@@ -178,10 +554,12 @@ mod tests {
         let url = "http://example.com/index.html".to_string();
         let expected = Ok(Url {
             url: url.clone(),
+            scheme: Scheme::Http,
             host: "example.com".to_string(),
             port: "80".to_string(),
             path: "index.html".to_string(),
             searchpart: "".to_string(),
+            fragment: "".to_string(),
         });
 
         assert_eq!(expected, Url::new(url).parse().cloned());
@@ -192,28 +570,304 @@ mod tests {
         let url = "http://example.com:8888/index.html?a=123&b=456".to_string();
         let expected = Ok(Url {
             url: url.clone(),
+            scheme: Scheme::Http,
             host: "example.com".to_string(),
             port: "8888".to_string(),
             path: "index.html".to_string(),
             searchpart: "a=123&b=456".to_string(),
+            fragment: "".to_string(),
         });
 
         assert_eq!(expected, Url::new(url).parse().cloned());
     }
 
+    #[test]
+    fn test_url_with_fragment() {
+        let url = "http://example.com/p?q=1#section".to_string();
+        let expected = Ok(Url {
+            url: url.clone(),
+            scheme: Scheme::Http,
+            host: "example.com".to_string(),
+            port: "80".to_string(),
+            path: "p".to_string(),
+            searchpart: "q=1".to_string(),
+            fragment: "section".to_string(),
+        });
+
+        assert_eq!(expected, Url::new(url).parse().cloned());
+    }
+
+    #[test]
+    fn test_url_ipv6_host_port() {
+        let url = "http://[::1]:8080/index.html".to_string();
+        let expected = Ok(Url {
+            url: url.clone(),
+            scheme: Scheme::Http,
+            host: "[::1]".to_string(),
+            port: "8080".to_string(),
+            path: "index.html".to_string(),
+            searchpart: "".to_string(),
+            fragment: "".to_string(),
+        });
+
+        assert_eq!(expected, Url::new(url).parse().cloned());
+    }
+
+    #[test]
+    fn test_host_parsed_domain() {
+        let url = Url::new("http://example.com".to_string())
+            .parse()
+            .cloned()
+            .unwrap();
+
+        assert_eq!(Host::Domain("example.com".to_string()), url.host_parsed());
+    }
+
+    #[test]
+    fn test_host_parsed_ipv4() {
+        let url = Url::new("http://127.0.0.1:8080".to_string())
+            .parse()
+            .cloned()
+            .unwrap();
+
+        assert_eq!(Host::Ipv4([127, 0, 0, 1]), url.host_parsed());
+    }
+
+    #[test]
+    fn test_host_parsed_ipv6() {
+        let url = Url::new("http://[::1]:8080".to_string())
+            .parse()
+            .cloned()
+            .unwrap();
+
+        assert_eq!(
+            Host::Ipv6([0, 0, 0, 0, 0, 0, 0, 1]),
+            url.host_parsed()
+        );
+    }
+
+    #[test]
+    fn test_host_parsed_ipv6_full() {
+        let url = Url::new("http://[2001:db8:0:0:0:0:0:1]".to_string())
+            .parse()
+            .cloned()
+            .unwrap();
+
+        assert_eq!(
+            Host::Ipv6([0x2001, 0x0db8, 0, 0, 0, 0, 0, 1]),
+            url.host_parsed()
+        );
+    }
+
     #[test]
     fn test_no_scheme() {
         let url = "example.com".to_string();
-        let expected = Err("Only HTTP is supported.".to_string());
+        let expected = Err("Unsupported scheme: ".to_string());
 
         assert_eq!(expected, Url::new(url).parse().cloned());
     }
 
     #[test]
     fn test_unsupported_scheme() {
-        let url = "https://example.com".to_string();
-        let expected = Err("Only HTTP is supported.".to_string());
+        let url = "ftp://example.com".to_string();
+        let expected = Err("Unsupported scheme: ftp".to_string());
 
         assert_eq!(expected, Url::new(url).parse().cloned());
     }
+
+    #[test]
+    fn test_https_scheme_default_port() {
+        let url = "https://example.com/index.html".to_string();
+        let expected = Ok(Url {
+            url: url.clone(),
+            scheme: Scheme::Https,
+            host: "example.com".to_string(),
+            port: "443".to_string(),
+            path: "index.html".to_string(),
+            searchpart: "".to_string(),
+            fragment: "".to_string(),
+        });
+
+        assert_eq!(expected, Url::new(url).parse().cloned());
+    }
+
+    #[test]
+    fn test_scheme_accessor() {
+        let url = Url::new("https://example.com".to_string())
+            .parse()
+            .cloned()
+            .unwrap();
+
+        assert_eq!(&Scheme::Https, url.scheme());
+    }
+
+    fn base_url() -> Url {
+        Url::new("http://a/b/c/d".to_string())
+            .parse()
+            .cloned()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_join_relative_path() {
+        let joined = base_url().join("g").unwrap();
+
+        assert_eq!("a", joined.host());
+        assert_eq!("b/c/g", joined.path());
+    }
+
+    #[test]
+    fn test_join_dot_segment() {
+        let joined = base_url().join("./g").unwrap();
+
+        assert_eq!("b/c/g", joined.path());
+    }
+
+    #[test]
+    fn test_join_dot_dot_segment() {
+        let joined = base_url().join("../g").unwrap();
+
+        assert_eq!("b/g", joined.path());
+    }
+
+    #[test]
+    fn test_join_dot_dot_beyond_root() {
+        let joined = base_url().join("../../../g").unwrap();
+
+        assert_eq!("g", joined.path());
+    }
+
+    #[test]
+    fn test_join_absolute_path() {
+        let joined = base_url().join("/g").unwrap();
+
+        assert_eq!("a", joined.host());
+        assert_eq!("g", joined.path());
+    }
+
+    #[test]
+    fn test_join_scheme_relative() {
+        let joined = base_url().join("//g").unwrap();
+
+        assert_eq!("g", joined.host());
+        assert_eq!("", joined.path());
+    }
+
+    #[test]
+    fn test_join_query_only() {
+        let joined = base_url().join("?y").unwrap();
+
+        assert_eq!("b/c/d", joined.path());
+        assert_eq!("y", joined.searchpart());
+    }
+
+    #[test]
+    fn test_join_path_and_query() {
+        let joined = base_url().join("g?y").unwrap();
+
+        assert_eq!("b/c/g", joined.path());
+        assert_eq!("y", joined.searchpart());
+    }
+
+    #[test]
+    fn test_join_fragment_only() {
+        let joined = base_url().join("#s").unwrap();
+
+        assert_eq!("b/c/d", joined.path());
+        assert_eq!("s", joined.fragment());
+    }
+
+    #[test]
+    fn test_join_absolute_scheme() {
+        let joined = base_url().join("http://other/x").unwrap();
+
+        assert_eq!("other", joined.host());
+        assert_eq!("x", joined.path());
+    }
+
+    #[test]
+    fn test_join_does_not_misclassify_embedded_scheme_as_absolute() {
+        let joined = base_url().join("redirect?to=http://evil").unwrap();
+
+        assert_eq!("a", joined.host());
+    }
+
+    #[test]
+    fn test_query_pairs() {
+        let url = Url::new("http://example.com/index.html?a=123&b=456".to_string())
+            .parse()
+            .cloned()
+            .unwrap();
+
+        let pairs: Vec<(String, String)> = url.query_pairs().collect();
+
+        assert_eq!(
+            vec![
+                ("a".to_string(), "123".to_string()),
+                ("b".to_string(), "456".to_string()),
+            ],
+            pairs
+        );
+    }
+
+    #[test]
+    fn test_query_pairs_missing_value() {
+        let url = Url::new("http://example.com/index.html?a".to_string())
+            .parse()
+            .cloned()
+            .unwrap();
+
+        let pairs: Vec<(String, String)> = url.query_pairs().collect();
+
+        assert_eq!(vec![("a".to_string(), "".to_string())], pairs);
+    }
+
+    #[test]
+    fn test_query_pairs_percent_and_plus_decoding() {
+        let url = Url::new("http://example.com/index.html?q=a+b%26c".to_string())
+            .parse()
+            .cloned()
+            .unwrap();
+
+        let pairs: Vec<(String, String)> = url.query_pairs().collect();
+
+        assert_eq!(vec![("q".to_string(), "a b&c".to_string())], pairs);
+    }
+
+    #[test]
+    fn test_query_pairs_invalid_percent_escape() {
+        let url = Url::new("http://example.com/index.html?q=100%".to_string())
+            .parse()
+            .cloned()
+            .unwrap();
+
+        let pairs: Vec<(String, String)> = url.query_pairs().collect();
+
+        assert_eq!(vec![("q".to_string(), "100%".to_string())], pairs);
+    }
+
+    #[test]
+    fn test_from_str() {
+        let url: Url = "http://example.com/p?q".parse().unwrap();
+
+        assert_eq!("example.com", url.host());
+        assert_eq!("p", url.path());
+        assert_eq!("q", url.searchpart());
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let original = "http://example.com:8080/index.html?a=1&b=2#frag";
+        let url: Url = original.parse().unwrap();
+
+        assert_eq!(original, url.to_string());
+    }
+
+    #[test]
+    fn test_display_round_trip_no_path() {
+        let original = "http://example.com";
+        let url: Url = original.parse().unwrap();
+
+        assert_eq!(original, url.to_string());
+    }
 }